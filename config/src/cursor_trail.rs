@@ -1,4 +1,60 @@
 use wezterm_dynamic::{FromDynamic, ToDynamic};
+use window::color::LinearRgba;
+
+/// Selects how the cursor trail effect is rendered
+#[derive(Debug, Copy, Clone, Eq, PartialEq, FromDynamic, ToDynamic)]
+pub enum CursorTrailStyle {
+    /// Animate a single quad whose corners deform towards the cursor
+    DeformQuad,
+    /// Record the cursor's recent path and render a ribbon that follows it
+    Ribbon,
+}
+
+impl Default for CursorTrailStyle {
+    fn default() -> Self {
+        Self::DeformQuad
+    }
+}
+
+/// Selects how the cursor trail composites against the cell background
+#[derive(Debug, Copy, Clone, Eq, PartialEq, FromDynamic, ToDynamic)]
+pub enum CursorTrailBlendMode {
+    /// Alpha-blend the trail over the cell background (the original effect)
+    Alpha,
+    /// Accumulate the trail color, scaled by its alpha, for a luminous,
+    /// glowing smear where overlapping segments get brighter
+    Additive,
+}
+
+impl Default for CursorTrailBlendMode {
+    fn default() -> Self {
+        Self::Alpha
+    }
+}
+
+/// Selects the easing curve used to animate the trail quad's corners
+/// towards the cursor
+#[derive(Debug, Copy, Clone, Eq, PartialEq, FromDynamic, ToDynamic)]
+pub enum CursorTrailEasing {
+    /// The original effect: corners approach the cursor exponentially,
+    /// covering a fixed fraction of the remaining distance per unit of
+    /// elapsed time
+    ExponentialDecay,
+    /// Corners decelerate smoothly into the cursor with no overshoot
+    EaseOutCubic,
+    /// Corners overshoot slightly past the cursor before settling back,
+    /// like a cubic ease-out with a small backswing
+    EaseOutBack,
+    /// Corners are driven by a damped spring towards the cursor, allowing
+    /// trailing corners to overshoot and oscillate before settling
+    Spring,
+}
+
+impl Default for CursorTrailEasing {
+    fn default() -> Self {
+        Self::ExponentialDecay
+    }
+}
 
 /// Configuration for cursor trail effect
 #[derive(Debug, Clone, FromDynamic, ToDynamic)]
@@ -8,8 +64,12 @@ pub struct CursorTrailConfig {
     pub enabled: bool,
 
     /// Cursor trail dwell time in milliseconds.
-    /// The trail animation only follows cursors that have stayed in their position
-    /// for longer than this value. This prevents trails during rapid cursor movements.
+    /// When `style` is `DeformQuad`, the trail only starts chasing cursors
+    /// that have stayed in their position for longer than this value, which
+    /// prevents trails during rapid cursor movements. When `style` is
+    /// `Ribbon`, the recorded stroke is cleared once the cursor has been
+    /// still for this long, so it behaves as a transient motion trail
+    /// rather than a permanent decal.
     #[dynamic(default = "default_dwell_threshold")]
     pub dwell_threshold: u64,
 
@@ -24,13 +84,75 @@ pub struct CursorTrailConfig {
     #[dynamic(default = "default_spread")]
     pub spread: f32,
 
-    /// Minimum distance (in cells) to trigger cursor trail
+    /// Minimum distance (in cells) to trigger cursor trail. Only applies
+    /// when `style` is `DeformQuad`; `Ribbon` uses `trail_length` and
+    /// `max_vertices` to control vertex spacing instead.
     #[dynamic(default = "default_distance_threshold")]
     pub distance_threshold: usize,
 
     /// Maximum opacity for cursor trail (0.0 to 1.0)
     #[dynamic(default = "default_opacity")]
     pub opacity: f32,
+
+    /// Color at the leading edge of the trail, closest to the cursor
+    #[dynamic(default = "default_color_start")]
+    pub color_start: LinearRgba,
+
+    /// Color at the trailing edge of the trail, furthest from the cursor
+    #[dynamic(default = "default_color_end")]
+    pub color_end: LinearRgba,
+
+    /// Selects how the trail is rendered: a single deforming quad (the
+    /// original effect), or a ribbon that follows the cursor's recorded path
+    #[dynamic(default)]
+    pub style: CursorTrailStyle,
+
+    /// Length of the trail ribbon, in cells, when `style` is `Ribbon`
+    #[dynamic(default = "default_trail_length")]
+    pub trail_length: f32,
+
+    /// Maximum number of vertices recorded along the ribbon path
+    #[dynamic(default = "default_max_vertices")]
+    pub max_vertices: usize,
+
+    /// Width of the ribbon trail, in cells, when `style` is `Ribbon`
+    #[dynamic(default = "default_trail_width")]
+    pub width: f32,
+
+    /// Distance from the cursor (in cells) within which the trail keeps
+    /// full opacity
+    #[dynamic(default = "default_fade_start_distance")]
+    pub fade_start_distance: f32,
+
+    /// Distance from the cursor (in cells) beyond which the trail is fully
+    /// transparent. Between `fade_start_distance` and this value, opacity
+    /// is linearly interpolated.
+    #[dynamic(default = "default_fade_end_distance")]
+    pub fade_end_distance: f32,
+
+    /// How the trail composites against the cell background: `Alpha`
+    /// blends it over the background, `Additive` accumulates it for a
+    /// glowing smear
+    #[dynamic(default)]
+    pub blend_mode: CursorTrailBlendMode,
+
+    /// Easing curve used to animate the trail quad's corners towards the
+    /// cursor. Only applies when `style` is `DeformQuad`. `duration` and
+    /// `spread` set the animation time for every easing except `Spring`,
+    /// which ignores them and is driven by `spring_stiffness` and
+    /// `spring_damping` instead.
+    #[dynamic(default)]
+    pub easing: CursorTrailEasing,
+
+    /// Spring stiffness used when `easing` is `Spring`. Higher values pull
+    /// corners towards the cursor more forcefully.
+    #[dynamic(default = "default_spring_stiffness")]
+    pub spring_stiffness: f32,
+
+    /// Spring damping used when `easing` is `Spring`. Higher values settle
+    /// oscillation faster; too low and corners will ring for longer.
+    #[dynamic(default = "default_spring_damping")]
+    pub spring_damping: f32,
 }
 
 impl CursorTrailConfig {
@@ -49,6 +171,42 @@ impl CursorTrailConfig {
                 self.opacity
             ));
         }
+        if self.max_vertices < 2 {
+            return Err(format!(
+                "cursor_trail.max_vertices must be at least 2 (got {})",
+                self.max_vertices
+            ));
+        }
+        if self.trail_length <= 0.0 {
+            return Err(format!(
+                "cursor_trail.trail_length must be > 0.0 (got {})",
+                self.trail_length
+            ));
+        }
+        if self.width <= 0.0 {
+            return Err(format!(
+                "cursor_trail.width must be > 0.0 (got {})",
+                self.width
+            ));
+        }
+        if self.fade_end_distance < self.fade_start_distance {
+            return Err(format!(
+                "cursor_trail.fade_end_distance ({}) must be >= fade_start_distance ({})",
+                self.fade_end_distance, self.fade_start_distance
+            ));
+        }
+        if self.spring_stiffness <= 0.0 {
+            return Err(format!(
+                "cursor_trail.spring_stiffness must be > 0.0 (got {})",
+                self.spring_stiffness
+            ));
+        }
+        if self.spring_damping < 0.0 {
+            return Err(format!(
+                "cursor_trail.spring_damping must be >= 0.0 (got {})",
+                self.spring_damping
+            ));
+        }
         Ok(())
     }
 }
@@ -62,6 +220,18 @@ impl Default for CursorTrailConfig {
             spread: default_spread(),
             distance_threshold: default_distance_threshold(),
             opacity: default_opacity(),
+            color_start: default_color_start(),
+            color_end: default_color_end(),
+            style: CursorTrailStyle::default(),
+            trail_length: default_trail_length(),
+            max_vertices: default_max_vertices(),
+            width: default_trail_width(),
+            fade_start_distance: default_fade_start_distance(),
+            fade_end_distance: default_fade_end_distance(),
+            blend_mode: CursorTrailBlendMode::default(),
+            easing: CursorTrailEasing::default(),
+            spring_stiffness: default_spring_stiffness(),
+            spring_damping: default_spring_damping(),
         }
     }
 }
@@ -85,3 +255,39 @@ fn default_dwell_threshold() -> u64 {
 fn default_opacity() -> f32 {
     0.8
 }
+
+fn default_trail_length() -> f32 {
+    8.0 // cells
+}
+
+fn default_max_vertices() -> usize {
+    16
+}
+
+fn default_trail_width() -> f32 {
+    1.0 // cells
+}
+
+fn default_color_start() -> LinearRgba {
+    LinearRgba(1.0, 1.0, 1.0, 1.0)
+}
+
+fn default_color_end() -> LinearRgba {
+    LinearRgba(1.0, 1.0, 1.0, 0.0)
+}
+
+fn default_fade_start_distance() -> f32 {
+    2.0 // cells
+}
+
+fn default_fade_end_distance() -> f32 {
+    8.0 // cells
+}
+
+fn default_spring_stiffness() -> f32 {
+    170.0
+}
+
+fn default_spring_damping() -> f32 {
+    20.0
+}
@@ -2,8 +2,11 @@ use crate::quad::{
     QuadImpl, QuadTrait, TripleLayerQuadAllocator, TripleLayerQuadAllocatorTrait, V_BOT_LEFT,
     V_BOT_RIGHT, V_TOP_LEFT, V_TOP_RIGHT,
 };
-use config::{CursorTrailConfig, HsbTransform};
+use config::{
+    CursorTrailBlendMode, CursorTrailConfig, CursorTrailEasing, CursorTrailStyle, HsbTransform,
+};
 use mux::renderable::StableCursorPosition;
+use std::collections::VecDeque;
 use std::ops::Range;
 use std::time::Instant;
 use wezterm_term::StableRowIndex;
@@ -13,6 +16,32 @@ use window::color::LinearRgba;
 /// Distance threshold for considering corners "at cursor"
 const SETTLED_THRESHOLD: f32 = 0.1;
 
+/// Width of the `Beam` cursor's trail box, as a fraction of cell width
+const BEAM_WIDTH_FRAC: f32 = 0.15;
+
+/// Height of the `Underline` cursor's trail box, as a fraction of cell height
+const UNDERLINE_HEIGHT_FRAC: f32 = 0.15;
+
+/// Thickness of each edge quad for the `HollowBlock` cursor, as a fraction
+/// of the cell dimension it is measured across (cell width for the left and
+/// right edges, cell height for the top and bottom edges)
+const HOLLOW_EDGE_THICKNESS_FRAC: f32 = 0.12;
+
+/// Below this speed (cells/sec), a spring-driven corner is considered to
+/// have stopped moving for the purposes of `settled`
+const SETTLED_VELOCITY_THRESHOLD: f32 = 0.05;
+
+/// The shape of the cursor the trail should track, so the trail's geometry
+/// matches the cursor's actual footprint rather than always being a filled
+/// 1x1 cell box.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CursorShape {
+    Block,
+    Beam,
+    Underline,
+    HollowBlock,
+}
+
 /// A screen position in f32 coordinates
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 struct Pos {
@@ -28,43 +57,148 @@ impl From<StableCursorPosition> for Pos {
     }
 }
 
+/// Linearly interpolate between two colors, clamping `t` to `[0, 1]`
+fn lerp_color(start: LinearRgba, end: LinearRgba, t: f32) -> LinearRgba {
+    let t = t.clamp(0.0, 1.0);
+    LinearRgba(
+        start.0 + (end.0 - start.0) * t,
+        start.1 + (end.1 - start.1) * t,
+        start.2 + (end.2 - start.2) * t,
+        start.3 + (end.3 - start.3) * t,
+    )
+}
+
+/// Euclidean distance between two cell-space positions
+fn distance(a: Pos, b: Pos) -> f32 {
+    let dx = a.x - b.x;
+    let dy = a.y - b.y;
+    (dx.powi(2) + dy.powi(2)).sqrt()
+}
+
+/// Prepare a vertex color for `blend_mode`. `Additive` premultiplies the
+/// color by its own alpha so that overlapping segments accumulate into a
+/// brighter glow instead of alpha-blending over one another.
+fn apply_blend_mode(color: LinearRgba, blend_mode: CursorTrailBlendMode) -> LinearRgba {
+    match blend_mode {
+        CursorTrailBlendMode::Alpha => color,
+        CursorTrailBlendMode::Additive => LinearRgba(
+            color.0 * color.3,
+            color.1 * color.3,
+            color.2 * color.3,
+            color.3,
+        ),
+    }
+}
+
+/// Ease-out-cubic: decelerates into `1.0` with no overshoot
+fn ease_out_cubic(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    1.0 - (1.0 - t).powi(3)
+}
+
+/// Ease-out-back: overshoots past `1.0` before settling back, giving a
+/// small backswing to the end of the motion
+fn ease_out_back(t: f32) -> f32 {
+    const C1: f32 = 1.70158;
+    const C3: f32 = C1 + 1.0;
+    let t = t.clamp(0.0, 1.0) - 1.0;
+    1.0 + C3 * t.powi(3) + C1 * t.powi(2)
+}
+
+/// Opacity multiplier for a point `distance` cells away from the cursor:
+/// 1.0 within `fade_start_distance`, 0.0 beyond `fade_end_distance`, and
+/// linearly interpolated in between.
+fn fade_alpha(distance: f32, fade_start_distance: f32, fade_end_distance: f32) -> f32 {
+    if fade_end_distance <= fade_start_distance {
+        return if distance <= fade_start_distance {
+            1.0
+        } else {
+            0.0
+        };
+    }
+    let t = (distance - fade_start_distance) / (fade_end_distance - fade_start_distance);
+    1.0 - t.clamp(0.0, 1.0)
+}
+
 /// The vertices for the trail quad
-#[derive(Debug, Default)]
-struct TrailQuad([Pos; 4]);
+#[derive(Debug, Default, Clone, Copy)]
+struct TrailQuad {
+    corners: [Pos; 4],
+
+    /// Per-corner position along the trail, normalized to `[0, 1]`, where
+    /// `0` is the leading edge (closest to the cursor) and `1` is the
+    /// trailing edge. Derived from the leading/trailing classification in
+    /// `interp` and used by `render` to drive the per-vertex color
+    /// gradient; the quad's shape is not tapered by it.
+    t: [f32; 4],
+
+    /// Per-corner velocity (cells/sec), only maintained when `easing` is
+    /// `Spring`; left at zero for all other easings.
+    velocity: [Pos; 4],
+}
 
 impl std::ops::Index<usize> for TrailQuad {
     type Output = Pos;
     fn index(&self, idx: usize) -> &Self::Output {
-        &self.0[idx]
+        &self.corners[idx]
     }
 }
 
 impl std::ops::IndexMut<usize> for TrailQuad {
     fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
-        &mut self.0[idx]
+        &mut self.corners[idx]
     }
 }
 
 impl TrailQuad {
-    fn at(p: Pos) -> Self {
-        Self([
-            Pos { x: p.x, y: p.y },
-            Pos {
-                x: p.x + 1.0,
-                y: p.y,
-            },
-            Pos {
-                x: p.x + 1.0,
-                y: p.y + 1.0,
-            },
-            Pos {
-                x: p.x,
-                y: p.y + 1.0,
-            },
-        ])
+    fn at(p: Pos, cell_width: f32, cursor_shape: CursorShape) -> Self {
+        let target = TrailTarget::at(p, cell_width, cursor_shape);
+        Self {
+            corners: [
+                Pos {
+                    x: target.left,
+                    y: target.top,
+                },
+                Pos {
+                    x: target.right,
+                    y: target.top,
+                },
+                Pos {
+                    x: target.right,
+                    y: target.bottom,
+                },
+                Pos {
+                    x: target.left,
+                    y: target.bottom,
+                },
+            ],
+            t: [0.0; 4],
+            velocity: [Pos::default(); 4],
+        }
     }
 
-    fn interp(&mut self, target: &TrailTarget, delta_time: f32, decay_fast: f32, decay_slow: f32) {
+    /// Animate the quad's corners towards `target`.
+    ///
+    /// Corners nearest the target (high `dot`) are classified as leading and
+    /// chase it over `duration_fast`; corners furthest from the target (low
+    /// `dot`) are trailing and chase it over `duration_slow`, giving the
+    /// smear/stretch effect. `easing` selects how progress over that
+    /// duration maps to a fraction of the distance covered; `start` is the
+    /// quad's corner positions at the moment `target` was last changed,
+    /// used as the baseline for the time-based easings, and `elapsed_since_move`
+    /// is the time since then.
+    fn interp(
+        &mut self,
+        target: &TrailTarget,
+        start: &TrailQuad,
+        delta_time: f32,
+        elapsed_since_move: f32,
+        duration_fast: f32,
+        duration_slow: f32,
+        easing: CursorTrailEasing,
+        spring_stiffness: f32,
+        spring_damping: f32,
+    ) {
         let target_x = [target.left, target.right, target.right, target.left];
         let target_y = [target.top, target.top, target.bottom, target.bottom];
 
@@ -79,10 +213,10 @@ impl TrailQuad {
         let mut dot = [0.0_f32; 4];
 
         for i in 0..4 {
-            dx[i] = target_x[i] - self.0[i].x;
-            dy[i] = target_y[i] - self.0[i].y;
+            dx[i] = target_x[i] - self.corners[i].x;
+            dy[i] = target_y[i] - self.corners[i].y;
 
-            if dx[i].abs() < 1e-6 && dy[i].abs() < 1e-6 {
+            if dx[i].abs() < 1e-6 && dy[i].abs() < 1e-6 && easing != CursorTrailEasing::Spring {
                 dx[i] = 0.0;
                 dy[i] = 0.0;
                 dot[i] = 0.0;
@@ -90,8 +224,12 @@ impl TrailQuad {
                 let norm = (dx[i].powi(2) + dy[i].powi(2)).sqrt();
                 let corner_to_center_x = target_x[i] - target_center_x;
                 let corner_to_center_y = target_y[i] - target_center_y;
-                dot[i] = (dx[i] * corner_to_center_x + dy[i] * corner_to_center_y)
-                    / (target_diag_2 * norm);
+                dot[i] = if norm < 1e-6 {
+                    0.0
+                } else {
+                    (dx[i] * corner_to_center_x + dy[i] * corner_to_center_y)
+                        / (target_diag_2 * norm)
+                };
             }
         }
 
@@ -99,25 +237,64 @@ impl TrailQuad {
         let max_dot = dot.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
 
         for i in 0..4 {
-            if (dx[i] == 0.0 && dy[i] == 0.0) || min_dot.is_infinite() {
+            // Corners nearest the target (high dot) are leading (t=0);
+            // corners furthest from the target (low dot) are trailing (t=1).
+            self.t[i] = if min_dot.is_infinite() || (max_dot - min_dot).abs() < 1e-6 {
+                0.0
+            } else {
+                1.0 - (dot[i] - min_dot) / (max_dot - min_dot)
+            };
+
+            if (dx[i] == 0.0 && dy[i] == 0.0 && easing != CursorTrailEasing::Spring)
+                || min_dot.is_infinite()
+            {
                 continue;
             }
 
-            let decay = if (max_dot - min_dot).abs() < 1e-6 {
-                decay_slow
+            let duration = if (max_dot - min_dot).abs() < 1e-6 {
+                duration_slow
             } else {
-                decay_slow + (decay_fast - decay_slow) * (dot[i] - min_dot) / (max_dot - min_dot)
+                duration_slow
+                    + (duration_fast - duration_slow) * (dot[i] - min_dot) / (max_dot - min_dot)
             };
 
-            let step = 1.0 - 2.0_f32.powf(-10.0 * delta_time / decay);
-            self.0[i].x += dx[i] * step;
-            self.0[i].y += dy[i] * step;
+            match easing {
+                CursorTrailEasing::ExponentialDecay => {
+                    let step = 1.0 - 2.0_f32.powf(-10.0 * delta_time / duration.max(1e-6));
+                    self.corners[i].x += dx[i] * step;
+                    self.corners[i].y += dy[i] * step;
+                }
+                CursorTrailEasing::EaseOutCubic | CursorTrailEasing::EaseOutBack => {
+                    let t = (elapsed_since_move / duration.max(1e-6)).clamp(0.0, 1.0);
+                    let progress = match easing {
+                        CursorTrailEasing::EaseOutCubic => ease_out_cubic(t),
+                        CursorTrailEasing::EaseOutBack => ease_out_back(t),
+                        _ => unreachable!(),
+                    };
+                    self.corners[i].x =
+                        start.corners[i].x + (target_x[i] - start.corners[i].x) * progress;
+                    self.corners[i].y =
+                        start.corners[i].y + (target_y[i] - start.corners[i].y) * progress;
+                }
+                CursorTrailEasing::Spring => {
+                    let offset_x = self.corners[i].x - target_x[i];
+                    let offset_y = self.corners[i].y - target_y[i];
+                    self.velocity[i].x +=
+                        (-spring_stiffness * offset_x - spring_damping * self.velocity[i].x)
+                            * delta_time;
+                    self.velocity[i].y +=
+                        (-spring_stiffness * offset_y - spring_damping * self.velocity[i].y)
+                            * delta_time;
+                    self.corners[i].x += self.velocity[i].x * delta_time;
+                    self.corners[i].y += self.velocity[i].y * delta_time;
+                }
+            }
         }
     }
 }
 
 /// The edges to animate a TrailQuad towards
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 struct TrailTarget {
     top: f32,
     bottom: f32,
@@ -125,12 +302,29 @@ struct TrailTarget {
     right: f32,
 }
 impl TrailTarget {
-    fn at(p: Pos) -> Self {
-        Self {
-            top: p.y,
-            bottom: p.y + 1.0,
-            left: p.x,
-            right: p.x + 1.0,
+    /// Build the target rectangle for a cursor at `p` with the given cell
+    /// width (1.0 or 2.0, for double-width glyphs) and on-screen shape, so
+    /// the trail hugs the cursor's actual footprint.
+    fn at(p: Pos, cell_width: f32, cursor_shape: CursorShape) -> Self {
+        match cursor_shape {
+            CursorShape::Block | CursorShape::HollowBlock => Self {
+                top: p.y,
+                bottom: p.y + 1.0,
+                left: p.x,
+                right: p.x + cell_width,
+            },
+            CursorShape::Beam => Self {
+                top: p.y,
+                bottom: p.y + 1.0,
+                left: p.x,
+                right: p.x + BEAM_WIDTH_FRAC,
+            },
+            CursorShape::Underline => Self {
+                top: p.y + (1.0 - UNDERLINE_HEIGHT_FRAC),
+                bottom: p.y + 1.0,
+                left: p.x,
+                right: p.x + cell_width,
+            },
         }
     }
 }
@@ -140,21 +334,42 @@ pub struct TickContext {
     cursor_pos: Pos,
     now: Instant,
     distance_threshold: f32,
-    decay_fast: f32,
-    decay_slow: f32,
+    duration_fast: f32,
+    duration_slow: f32,
     dwell_treshold: u64,
+    style: CursorTrailStyle,
+    trail_length: f32,
+    max_vertices: usize,
+    cell_width: f32,
+    cursor_shape: CursorShape,
+    easing: CursorTrailEasing,
+    spring_stiffness: f32,
+    spring_damping: f32,
 }
 
 impl TickContext {
-    pub fn from_cursor(cursor_pos: StableCursorPosition, trail_config: &CursorTrailConfig) -> Self {
+    pub fn from_cursor(
+        cursor_pos: StableCursorPosition,
+        trail_config: &CursorTrailConfig,
+        cell_width: u8,
+        cursor_shape: CursorShape,
+    ) -> Self {
         let float_dur = trail_config.duration as f32;
         Self {
             cursor_pos: cursor_pos.into(),
             now: Instant::now(), // todo secs and such or take reference?
             distance_threshold: trail_config.distance_threshold as f32,
-            decay_fast: float_dur / 1000.0,
-            decay_slow: (float_dur * trail_config.spread) / 1000.0,
+            duration_fast: float_dur / 1000.0,
+            duration_slow: (float_dur * trail_config.spread) / 1000.0,
             dwell_treshold: trail_config.dwell_threshold,
+            style: trail_config.style,
+            trail_length: trail_config.trail_length,
+            max_vertices: trail_config.max_vertices,
+            cell_width: cell_width as f32,
+            cursor_shape,
+            easing: trail_config.easing,
+            spring_stiffness: trail_config.spring_stiffness,
+            spring_damping: trail_config.spring_damping,
         }
     }
 }
@@ -169,6 +384,19 @@ pub struct CursorTrail {
     // todo: structify
     target: TrailTarget,
 
+    /// The raw cursor position (not shape-adjusted, unlike `target`) that
+    /// `target` was last derived from. Used to detect small cursor moves
+    /// without being biased by shapes like `Underline` whose target box is
+    /// offset from the cursor's actual cell.
+    target_cursor_pos: Pos,
+
+    /// The quad's corner positions at the moment `target` was last changed;
+    /// the baseline for the time-based easings (`EaseOutCubic`/`EaseOutBack`)
+    quad_at_move_start: TrailQuad,
+
+    /// When `target` was last changed
+    move_started_at: Instant,
+
     /// Last cursor position
     last_cursor_pos: Pos,
 
@@ -177,6 +405,12 @@ pub struct CursorTrail {
 
     /// Timestamp of last update
     updated_at: Instant,
+
+    /// Recorded cursor positions for the `Ribbon` style, oldest first
+    ribbon: VecDeque<Pos>,
+
+    /// Cursor position at which the most recent ribbon vertex was recorded
+    last_vertex_at: Pos,
 }
 
 impl CursorTrail {
@@ -185,9 +419,42 @@ impl CursorTrail {
         Self {
             quad: TrailQuad::default(),
             target: TrailTarget::default(),
+            target_cursor_pos: Pos::default(),
+            quad_at_move_start: TrailQuad::default(),
+            move_started_at: now,
             last_cursor_pos: Pos::default(),
             cursor_last_moved: now,
             updated_at: now,
+            ribbon: VecDeque::new(),
+            last_vertex_at: Pos::default(),
+        }
+    }
+
+    /// Append `cursor_pos` to the ribbon if it is far enough from the last
+    /// recorded vertex, keeping vertices roughly evenly spaced and bounding
+    /// the buffer to `max_vertices` entries.
+    fn record_ribbon_vertex(&mut self, cursor_pos: Pos, trail_length: f32, max_vertices: usize) {
+        if max_vertices == 0 {
+            return;
+        }
+
+        if self.ribbon.is_empty() {
+            self.ribbon.push_back(cursor_pos);
+            self.last_vertex_at = cursor_pos;
+            return;
+        }
+
+        let dx = cursor_pos.x - self.last_vertex_at.x;
+        let dy = cursor_pos.y - self.last_vertex_at.y;
+        let distance = (dx.powi(2) + dy.powi(2)).sqrt();
+        let spacing = trail_length / max_vertices as f32;
+
+        if distance > spacing {
+            self.ribbon.push_back(cursor_pos);
+            self.last_vertex_at = cursor_pos;
+            if self.ribbon.len() > max_vertices {
+                self.ribbon.pop_front();
+            }
         }
     }
 
@@ -201,18 +468,42 @@ impl CursorTrail {
             self.last_cursor_pos = ctx.cursor_pos;
         }
 
+        if ctx.style == CursorTrailStyle::Ribbon {
+            let dwell_time = ctx.now.duration_since(self.cursor_last_moved).as_millis() as u64;
+            if dwell_time >= ctx.dwell_treshold {
+                // The cursor has settled: let the stroke disappear rather than
+                // sit on screen as a permanent decal, mirroring DeformQuad's
+                // settled()-gated return to `false` once it has caught up.
+                self.ribbon.clear();
+                return false;
+            }
+
+            self.record_ribbon_vertex(ctx.cursor_pos, ctx.trail_length, ctx.max_vertices);
+            return self.ribbon.len() >= 2;
+        }
+
         if self.target.left == 0.0 && self.target.right == 0.0 {
-            self.target = TrailTarget::at(ctx.cursor_pos);
-            self.quad = TrailQuad::at(ctx.cursor_pos);
+            self.target = TrailTarget::at(ctx.cursor_pos, ctx.cell_width, ctx.cursor_shape);
+            self.target_cursor_pos = ctx.cursor_pos;
+            self.quad = TrailQuad::at(ctx.cursor_pos, ctx.cell_width, ctx.cursor_shape);
+            self.quad_at_move_start = self.quad;
+            self.move_started_at = ctx.now;
             return false;
         }
 
-        let distance_to_cursor = (ctx.cursor_pos.x - self.target.left).abs()
-            + (ctx.cursor_pos.y - self.target.top).abs();
+        // Compared against the raw cursor position `target` was last built
+        // from, not `target.left/top` themselves: shapes like `Underline`
+        // offset the target box from the cursor's actual cell, which would
+        // otherwise leave a constant residual distance even when the cursor
+        // hasn't moved at all.
+        let distance_to_cursor = distance(ctx.cursor_pos, self.target_cursor_pos);
 
         if distance_to_cursor > 0.0 && distance_to_cursor <= ctx.distance_threshold {
-            self.target = TrailTarget::at(ctx.cursor_pos);
-            self.quad = TrailQuad::at(ctx.cursor_pos);
+            self.target = TrailTarget::at(ctx.cursor_pos, ctx.cell_width, ctx.cursor_shape);
+            self.target_cursor_pos = ctx.cursor_pos;
+            self.quad = TrailQuad::at(ctx.cursor_pos, ctx.cell_width, ctx.cursor_shape);
+            self.quad_at_move_start = self.quad;
+            self.move_started_at = ctx.now;
             return false;
         }
 
@@ -220,11 +511,28 @@ impl CursorTrail {
         let dwelled = dwell_time >= ctx.dwell_treshold;
 
         if dwelled {
-            self.target = TrailTarget::at(ctx.cursor_pos);
+            let new_target = TrailTarget::at(ctx.cursor_pos, ctx.cell_width, ctx.cursor_shape);
+            if new_target != self.target {
+                self.quad_at_move_start = self.quad;
+                self.move_started_at = ctx.now;
+            }
+            self.target = new_target;
+            self.target_cursor_pos = ctx.cursor_pos;
         }
 
-        self.quad
-            .interp(&self.target, delta_time, ctx.decay_fast, ctx.decay_slow);
+        let elapsed_since_move = ctx.now.duration_since(self.move_started_at).as_secs_f32();
+
+        self.quad.interp(
+            &self.target,
+            &self.quad_at_move_start,
+            delta_time,
+            elapsed_since_move,
+            ctx.duration_fast,
+            ctx.duration_slow,
+            ctx.easing,
+            ctx.spring_stiffness,
+            ctx.spring_damping,
+        );
 
         !self.settled(SETTLED_THRESHOLD) || !dwelled
     }
@@ -246,6 +554,11 @@ impl CursorTrail {
             if dx.abs() > threshold || dy.abs() > threshold {
                 return false;
             }
+
+            let speed = (self.quad.velocity[i].x.powi(2) + self.quad.velocity[i].y.powi(2)).sqrt();
+            if speed > SETTLED_VELOCITY_THRESHOLD {
+                return false;
+            }
         }
         true
     }
@@ -259,10 +572,37 @@ impl CursorTrail {
         stable_range: Range<StableRowIndex>,
         window_dimensions: (f32, f32), // (width, height)
         pixel_offset: (f32, f32),      // (left_pixel_x, top_pixel_y)
-        trail_color: LinearRgba,
+        color_start: LinearRgba,
+        color_end: LinearRgba,
         hsv_transform: Option<HsbTransform>,
         white_space_texture: TextureRect,
+        style: CursorTrailStyle,
+        width: f32,
+        fade_start_distance: f32,
+        fade_end_distance: f32,
+        cursor_shape: CursorShape,
+        blend_mode: CursorTrailBlendMode,
     ) -> anyhow::Result<()> {
+        if style == CursorTrailStyle::Ribbon {
+            return self.render_ribbon(
+                layers,
+                cell_width,
+                cell_height,
+                pane_left,
+                stable_range,
+                window_dimensions,
+                pixel_offset,
+                color_start,
+                color_end,
+                hsv_transform,
+                white_space_texture,
+                width,
+                fade_start_distance,
+                fade_end_distance,
+                blend_mode,
+            );
+        }
+
         let (window_width, window_height) = window_dimensions;
         let (left_pixel_x, top_pixel_y) = pixel_offset;
 
@@ -289,6 +629,33 @@ impl CursorTrail {
             ],
         ];
 
+        // Corner 0/1/3/2 map to TOP_LEFT/TOP_RIGHT/BOT_LEFT/BOT_RIGHT (see
+        // the pixel_corners ordering above); carry the same mapping across
+        // to the leading/trailing `t` value computed in `interp` so each
+        // vertex gets the color for its own position along the trail. The
+        // same trailing corners (high `t`) tend to end up furthest from the
+        // cursor, so fading by actual distance naturally dissolves the tail.
+        let corner_order = [0, 1, 3, 2];
+        let vertex_colors = corner_order.map(|i| {
+            let mut color = lerp_color(color_start, color_end, self.quad.t[i]);
+            let d = distance(self.quad[i], self.last_cursor_pos);
+            color.3 *= fade_alpha(d, fade_start_distance, fade_end_distance);
+            apply_blend_mode(color, blend_mode)
+        });
+
+        if cursor_shape == CursorShape::HollowBlock {
+            return self.render_hollow_edges(
+                layers,
+                cell_width,
+                cell_height,
+                pixel_corners,
+                vertex_colors,
+                hsv_transform,
+                white_space_texture,
+                blend_mode,
+            );
+        }
+
         let mut quad_impl = layers.allocate(0)?;
 
         match &mut quad_impl {
@@ -297,15 +664,386 @@ impl CursorTrail {
                 quad.vert[V_TOP_RIGHT].position = pixel_corners[1];
                 quad.vert[V_BOT_LEFT].position = pixel_corners[2];
                 quad.vert[V_BOT_RIGHT].position = pixel_corners[3];
+
+                quad.vert[V_TOP_LEFT].color = vertex_colors[0];
+                quad.vert[V_TOP_RIGHT].color = vertex_colors[1];
+                quad.vert[V_BOT_LEFT].color = vertex_colors[2];
+                quad.vert[V_BOT_RIGHT].color = vertex_colors[3];
             }
             QuadImpl::Boxed(_) => {}
         }
 
         quad_impl.set_hsv(hsv_transform);
-        quad_impl.set_is_background();
+        if blend_mode == CursorTrailBlendMode::Alpha {
+            quad_impl.set_is_background();
+        }
         quad_impl.set_texture(white_space_texture);
-        quad_impl.set_fg_color(trail_color);
 
         Ok(())
     }
+
+    /// Render the `HollowBlock` cursor shape as four thin edge quads
+    /// outlining `pixel_corners` (in TOP_LEFT, TOP_RIGHT, BOT_LEFT,
+    /// BOT_RIGHT order) instead of one filled quad.
+    fn render_hollow_edges(
+        &self,
+        layers: &mut TripleLayerQuadAllocator,
+        cell_width: f32,
+        cell_height: f32,
+        pixel_corners: [[f32; 2]; 4],
+        vertex_colors: [LinearRgba; 4],
+        hsv_transform: Option<HsbTransform>,
+        white_space_texture: TextureRect,
+        blend_mode: CursorTrailBlendMode,
+    ) -> anyhow::Result<()> {
+        let [top_left, top_right, bot_left, bot_right] = pixel_corners;
+        let [color_tl, color_tr, color_bl, color_br] = vertex_colors;
+
+        let thickness_x = cell_width * HOLLOW_EDGE_THICKNESS_FRAC;
+        let thickness_y = cell_height * HOLLOW_EDGE_THICKNESS_FRAC;
+
+        let inner_tl = [top_left[0] + thickness_x, top_left[1] + thickness_y];
+        let inner_tr = [top_right[0] - thickness_x, top_right[1] + thickness_y];
+        let inner_bl = [bot_left[0] + thickness_x, bot_left[1] - thickness_y];
+        let inner_br = [bot_right[0] - thickness_x, bot_right[1] - thickness_y];
+
+        // (top_left, top_right, bottom_left, bottom_right, color_top_left,
+        // color_top_right, color_bottom_left, color_bottom_right) for each edge quad
+        let edges = [
+            (top_left, top_right, inner_tl, inner_tr, color_tl, color_tr, color_tl, color_tr),
+            (inner_bl, inner_br, bot_left, bot_right, color_bl, color_br, color_bl, color_br),
+            (top_left, inner_tl, bot_left, inner_bl, color_tl, color_tl, color_bl, color_bl),
+            (inner_tr, top_right, inner_br, bot_right, color_tr, color_tr, color_br, color_br),
+        ];
+
+        for (tl, tr, bl, br, c_tl, c_tr, c_bl, c_br) in edges {
+            let mut quad_impl = layers.allocate(0)?;
+
+            match &mut quad_impl {
+                QuadImpl::Vert(quad) => {
+                    quad.vert[V_TOP_LEFT].position = tl;
+                    quad.vert[V_TOP_RIGHT].position = tr;
+                    quad.vert[V_BOT_LEFT].position = bl;
+                    quad.vert[V_BOT_RIGHT].position = br;
+
+                    quad.vert[V_TOP_LEFT].color = c_tl;
+                    quad.vert[V_TOP_RIGHT].color = c_tr;
+                    quad.vert[V_BOT_LEFT].color = c_bl;
+                    quad.vert[V_BOT_RIGHT].color = c_br;
+                }
+                QuadImpl::Boxed(_) => {}
+            }
+
+            quad_impl.set_hsv(hsv_transform);
+            if blend_mode == CursorTrailBlendMode::Alpha {
+                quad_impl.set_is_background();
+            }
+            quad_impl.set_texture(white_space_texture);
+        }
+
+        Ok(())
+    }
+
+    /// Render the `Ribbon` style: a triangle strip following the recorded
+    /// cursor path, built from one quad per consecutive pair of vertices.
+    fn render_ribbon(
+        &self,
+        layers: &mut TripleLayerQuadAllocator,
+        cell_width: f32,
+        cell_height: f32,
+        pane_left: usize,
+        stable_range: Range<StableRowIndex>,
+        window_dimensions: (f32, f32), // (width, height)
+        pixel_offset: (f32, f32),      // (left_pixel_x, top_pixel_y)
+        color_start: LinearRgba,
+        color_end: LinearRgba,
+        hsv_transform: Option<HsbTransform>,
+        white_space_texture: TextureRect,
+        width: f32,
+        fade_start_distance: f32,
+        fade_end_distance: f32,
+        blend_mode: CursorTrailBlendMode,
+    ) -> anyhow::Result<()> {
+        if self.ribbon.len() < 2 {
+            return Ok(());
+        }
+
+        let (window_width, window_height) = window_dimensions;
+        let (left_pixel_x, top_pixel_y) = pixel_offset;
+
+        // Convert corner positions from cell coordinates to pixel coordinates
+        let px_x = (window_width / -2.0) + left_pixel_x;
+        let px_y = (window_height / -2.0) + top_pixel_y;
+
+        let to_pixel = |p: Pos| -> [f32; 2] {
+            [
+                px_x + (p.x - pane_left as f32) * cell_width,
+                px_y + (p.y - stable_range.start as f32) * cell_height,
+            ]
+        };
+
+        // `ribbon` is recorded oldest-first, so the last entry is closest to
+        // the cursor (the leading edge, t=0) and the first is the trailing
+        // tail (t=1).
+        let last_index = self.ribbon.len() - 1;
+        let t_at = |index: usize| -> f32 { 1.0 - (index as f32 / last_index as f32) };
+
+        // Ease-out taper so the ribbon narrows towards its tail rather than
+        // shrinking linearly.
+        let width_at = |t: f32| -> f32 {
+            let t = t.clamp(0.0, 1.0);
+            width * (1.0 - t * t)
+        };
+
+        let pairs = self.ribbon.iter().copied().zip(self.ribbon.iter().copied().skip(1));
+        for (index, (p0, p1)) in pairs.enumerate() {
+            let dx = p1.x - p0.x;
+            let dy = p1.y - p0.y;
+            let len = (dx.powi(2) + dy.powi(2)).sqrt();
+            if len < 1e-6 {
+                continue;
+            }
+
+            let t0 = t_at(index);
+            let t1 = t_at(index + 1);
+            let half_width0 = width_at(t0) * 0.5;
+            let half_width1 = width_at(t1) * 0.5;
+            let mut color0 = lerp_color(color_start, color_end, t0);
+            let mut color1 = lerp_color(color_start, color_end, t1);
+            color0.3 *= fade_alpha(
+                distance(p0, self.last_cursor_pos),
+                fade_start_distance,
+                fade_end_distance,
+            );
+            color1.3 *= fade_alpha(
+                distance(p1, self.last_cursor_pos),
+                fade_start_distance,
+                fade_end_distance,
+            );
+            let color0 = apply_blend_mode(color0, blend_mode);
+            let color1 = apply_blend_mode(color1, blend_mode);
+
+            // Perpendicular to the segment direction, used to offset each
+            // side of the strip by that endpoint's own tapered half-width.
+            let perp_x = -dy / len;
+            let perp_y = dx / len;
+
+            let pixel_corners = [
+                to_pixel(Pos {
+                    x: p0.x + perp_x * half_width0,
+                    y: p0.y + perp_y * half_width0,
+                }),
+                to_pixel(Pos {
+                    x: p1.x + perp_x * half_width1,
+                    y: p1.y + perp_y * half_width1,
+                }),
+                to_pixel(Pos {
+                    x: p0.x - perp_x * half_width0,
+                    y: p0.y - perp_y * half_width0,
+                }),
+                to_pixel(Pos {
+                    x: p1.x - perp_x * half_width1,
+                    y: p1.y - perp_y * half_width1,
+                }),
+            ];
+
+            let mut quad_impl = layers.allocate(0)?;
+
+            match &mut quad_impl {
+                QuadImpl::Vert(quad) => {
+                    quad.vert[V_TOP_LEFT].position = pixel_corners[0];
+                    quad.vert[V_TOP_RIGHT].position = pixel_corners[1];
+                    quad.vert[V_BOT_LEFT].position = pixel_corners[2];
+                    quad.vert[V_BOT_RIGHT].position = pixel_corners[3];
+
+                    quad.vert[V_TOP_LEFT].color = color0;
+                    quad.vert[V_TOP_RIGHT].color = color1;
+                    quad.vert[V_BOT_LEFT].color = color0;
+                    quad.vert[V_BOT_RIGHT].color = color1;
+                }
+                QuadImpl::Boxed(_) => {}
+            }
+
+            quad_impl.set_hsv(hsv_transform);
+            if blend_mode == CursorTrailBlendMode::Alpha {
+                quad_impl.set_is_background();
+            }
+            quad_impl.set_texture(white_space_texture);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ease_out_cubic_clamps_and_reaches_endpoints() {
+        assert_eq!(ease_out_cubic(0.0), 0.0);
+        assert!((ease_out_cubic(1.0) - 1.0).abs() < 1e-6);
+        assert_eq!(ease_out_cubic(-1.0), ease_out_cubic(0.0));
+        assert_eq!(ease_out_cubic(2.0), ease_out_cubic(1.0));
+        // Ease-out: more progress up front than a linear ramp would give.
+        assert!(ease_out_cubic(0.5) > 0.5);
+    }
+
+    #[test]
+    fn ease_out_back_overshoots_before_settling() {
+        assert_eq!(ease_out_back(0.0), 0.0);
+        assert!((ease_out_back(1.0) - 1.0).abs() < 1e-6);
+        assert_eq!(ease_out_back(-1.0), ease_out_back(0.0));
+        assert_eq!(ease_out_back(2.0), ease_out_back(1.0));
+
+        let overshoots = (1..100)
+            .map(|i| i as f32 / 100.0)
+            .any(|t| ease_out_back(t) > 1.0);
+        assert!(
+            overshoots,
+            "ease_out_back should overshoot past 1.0 before settling at t=1"
+        );
+    }
+
+    #[test]
+    fn fade_alpha_is_full_within_start_and_zero_beyond_end() {
+        assert_eq!(fade_alpha(0.0, 2.0, 8.0), 1.0);
+        assert_eq!(fade_alpha(2.0, 2.0, 8.0), 1.0);
+        assert_eq!(fade_alpha(8.0, 2.0, 8.0), 0.0);
+        assert_eq!(fade_alpha(20.0, 2.0, 8.0), 0.0);
+        assert!((fade_alpha(5.0, 2.0, 8.0) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn fade_alpha_degenerate_range_is_a_step() {
+        // fade_end_distance <= fade_start_distance must not divide by zero;
+        // it should behave like a step function instead.
+        assert_eq!(fade_alpha(1.0, 5.0, 5.0), 1.0);
+        assert_eq!(fade_alpha(6.0, 5.0, 5.0), 0.0);
+    }
+
+    #[test]
+    fn lerp_color_interpolates_and_clamps_t() {
+        let start = LinearRgba(1.0, 1.0, 1.0, 1.0);
+        let end = LinearRgba(0.0, 0.0, 0.0, 0.0);
+        assert_eq!(lerp_color(start, end, 0.0), start);
+        assert_eq!(lerp_color(start, end, 1.0), end);
+        assert_eq!(lerp_color(start, end, -1.0), start);
+        assert_eq!(lerp_color(start, end, 2.0), end);
+
+        let mid = lerp_color(start, end, 0.5);
+        assert!((mid.0 - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn record_ribbon_vertex_respects_spacing_and_eviction() {
+        let mut trail = CursorTrail::new();
+        let trail_length = 8.0;
+        let max_vertices = 4;
+        let spacing = trail_length / max_vertices as f32;
+
+        trail.record_ribbon_vertex(Pos { x: 0.0, y: 0.0 }, trail_length, max_vertices);
+        assert_eq!(trail.ribbon.len(), 1);
+
+        // Within the spacing threshold: no new vertex recorded.
+        trail.record_ribbon_vertex(
+            Pos {
+                x: spacing / 2.0,
+                y: 0.0,
+            },
+            trail_length,
+            max_vertices,
+        );
+        assert_eq!(trail.ribbon.len(), 1);
+
+        // Past the spacing threshold: a new vertex is recorded.
+        trail.record_ribbon_vertex(
+            Pos {
+                x: spacing + 0.1,
+                y: 0.0,
+            },
+            trail_length,
+            max_vertices,
+        );
+        assert_eq!(trail.ribbon.len(), 2);
+
+        // Keep moving far enough to push past max_vertices and confirm the
+        // oldest vertices are evicted rather than growing unbounded.
+        for i in 2..8 {
+            let x = i as f32 * (spacing + 0.1);
+            trail.record_ribbon_vertex(Pos { x, y: 0.0 }, trail_length, max_vertices);
+        }
+        assert_eq!(trail.ribbon.len(), max_vertices);
+    }
+
+    #[test]
+    fn spring_easing_converges_and_settles_with_damping() {
+        let target = TrailTarget {
+            top: 0.0,
+            bottom: 1.0,
+            left: 0.0,
+            right: 1.0,
+        };
+        let mut quad = TrailQuad::at(Pos { x: 5.0, y: 5.0 }, 1.0, CursorShape::Block);
+        let start = quad;
+
+        let dt = 1.0 / 120.0;
+        let mut settled_tail = true;
+        for step in 0..2000 {
+            quad.interp(
+                &target, &start, dt, 0.0, 0.0, 0.0, CursorTrailEasing::Spring, 170.0, 20.0,
+            );
+            if step >= 1900 {
+                let dx = quad.corners[0].x - target.left;
+                let dy = quad.corners[0].y - target.top;
+                let speed =
+                    (quad.velocity[0].x.powi(2) + quad.velocity[0].y.powi(2)).sqrt();
+                if dx.abs() > SETTLED_THRESHOLD
+                    || dy.abs() > SETTLED_THRESHOLD
+                    || speed > SETTLED_VELOCITY_THRESHOLD
+                {
+                    settled_tail = false;
+                }
+            }
+        }
+        assert!(
+            settled_tail,
+            "a damped spring should converge onto the target and stop moving"
+        );
+    }
+
+    #[test]
+    fn spring_easing_without_damping_never_settles() {
+        // `CursorTrailConfig::validate` permits `spring_damping == 0.0`; pin
+        // down that this produces an undamped oscillator that keeps moving
+        // indefinitely rather than silently converging like the damped case
+        // above.
+        let target = TrailTarget {
+            top: 0.0,
+            bottom: 1.0,
+            left: 0.0,
+            right: 1.0,
+        };
+        let mut quad = TrailQuad::at(Pos { x: 5.0, y: 5.0 }, 1.0, CursorShape::Block);
+        let start = quad;
+
+        let dt = 1.0 / 120.0;
+        let mut still_oscillating = false;
+        for step in 0..2000 {
+            quad.interp(
+                &target, &start, dt, 0.0, 0.0, 0.0, CursorTrailEasing::Spring, 170.0, 0.0,
+            );
+            if step >= 1900 {
+                let speed =
+                    (quad.velocity[0].x.powi(2) + quad.velocity[0].y.powi(2)).sqrt();
+                if speed > SETTLED_VELOCITY_THRESHOLD {
+                    still_oscillating = true;
+                }
+            }
+        }
+        assert!(
+            still_oscillating,
+            "an undamped spring (spring_damping = 0) should keep oscillating, not settle"
+        );
+    }
 }